@@ -48,10 +48,18 @@ impl AccountId32 {
     pub fn new(value: [u8; 32]) -> Self {
         AccountId32(value)
     }
-    // Return the ss58-check string for this key. Adapted from `sp_core::crypto`.
+    // Return the ss58-check string for this key using the crate's default network prefix.
+    // Adapted from `sp_core::crypto`.
     pub fn to_ss58check(&self) -> String {
+        self.to_ss58check_with_prefix(crate::SS58_PREFIX)
+    }
+
+    // Return the ss58-check string for this key using the given network registry prefix. This lets
+    // a single key be rendered for a relay chain, a parachain, or a bridged account, each of which
+    // may register a different prefix.
+    pub fn to_ss58check_with_prefix(&self, prefix: u16) -> String {
         // We mask out the upper two bits of the ident - SS58 Prefix currently only supports 14-bits
-        let ident: u16 = crate::SS58_PREFIX & 0b0011_1111_1111_1111;
+        let ident: u16 = prefix & 0b0011_1111_1111_1111;
         let mut v = match ident {
             0..=63 => vec![ident as u8],
             64..=16_383 => {
@@ -72,8 +80,15 @@ impl AccountId32 {
 
     // This isn't strictly needed, but to give our AccountId32 a little more usefulness, we also
     // implement the logic needed to decode an AccountId32 from an SS58 encoded string. This is exposed
-    // via a `FromStr` impl.
+    // via a `FromStr` impl, which discards the recovered network prefix.
     fn from_ss58check(s: &str) -> Result<Self, FromSs58Error> {
+        Self::from_ss58check_with_prefix(s).map(|(account, _)| account)
+    }
+
+    // Decode an AccountId32 from an SS58 encoded string, also returning the network registry prefix
+    // recovered from the string. Unlike `from_ss58check` this keeps the prefix, so a caller touching
+    // several networks can tell which one an address belongs to.
+    pub fn from_ss58check_with_prefix(s: &str) -> Result<(Self, u16), FromSs58Error> {
         const CHECKSUM_LEN: usize = 2;
         let body_len = 32;
 
@@ -81,9 +96,15 @@ impl AccountId32 {
         if data.len() < 2 {
             return Err(FromSs58Error::BadLength);
         }
-        let prefix_len = match data[0] {
-            0..=63 => 1,
-            64..=127 => 2,
+        let (prefix_len, ident) = match data[0] {
+            0..=63 => (1, data[0] as u16),
+            64..=127 => {
+                // weird bit manipulation owing to the combination of LE encoding and missing two
+                // bits from the decoded sum (the inverse of the encoding in `to_ss58check_with_prefix`)
+                let lower = ((data[0] & 0b0011_1111) << 2) | (data[1] >> 6);
+                let upper = data[1] & 0b0011_1111;
+                (2, (lower as u16) | ((upper as u16) << 8))
+            }
             _ => return Err(FromSs58Error::InvalidPrefix),
         };
         if data.len() != prefix_len + body_len + CHECKSUM_LEN {
@@ -98,7 +119,7 @@ impl AccountId32 {
         let result = data[prefix_len..body_len + prefix_len]
             .try_into()
             .map_err(|_| FromSs58Error::BadLength)?;
-        Ok(AccountId32(result))
+        Ok((AccountId32(result), ident))
     }
 }
 
@@ -199,4 +220,39 @@ mod tests {
             alice_utils_account_id.to_ss58check()
         );
     }
+
+    #[test]
+    fn test_account_conversion_to_ss58_with_single_byte_prefix() {
+        // prefix 0 is the Polkadot relay chain registry prefix, encoded in a single byte
+        let prefix: u16 = 0;
+        let alice_utils_account_id: AccountId32 = AccountKeyring::Alice.to_account_id().into();
+        let alice_sp_account_id: SpAccountId = AccountKeyring::Alice.to_account_id();
+        assert_eq!(
+            alice_sp_account_id.to_ss58check_with_version(prefix.into()),
+            alice_utils_account_id.to_ss58check_with_prefix(prefix)
+        );
+    }
+
+    #[test]
+    fn test_account_conversion_to_ss58_with_two_byte_prefix() {
+        // prefix 2007 (Kulupu) needs two bytes to encode
+        let prefix: u16 = 2007;
+        let alice_utils_account_id: AccountId32 = AccountKeyring::Alice.to_account_id().into();
+        let alice_sp_account_id: SpAccountId = AccountKeyring::Alice.to_account_id();
+        assert_eq!(
+            alice_sp_account_id.to_ss58check_with_version(prefix.into()),
+            alice_utils_account_id.to_ss58check_with_prefix(prefix)
+        );
+    }
+
+    #[test]
+    fn test_ss58_roundtrip_recovers_prefix() {
+        for prefix in [0u16, SS58_PREFIX, 2007] {
+            let account: AccountId32 = AccountKeyring::Alice.to_account_id().into();
+            let encoded = account.to_ss58check_with_prefix(prefix);
+            let (decoded, recovered) = AccountId32::from_ss58check_with_prefix(&encoded).unwrap();
+            assert_eq!(decoded, account);
+            assert_eq!(recovered, prefix);
+        }
+    }
 }
\ No newline at end of file
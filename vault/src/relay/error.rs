@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+/// Errors surfaced by the Bitcoin block-header relay.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// A block hash returned by the parachain could not be decoded.
+    #[error("Failed to decode block hash")]
+    DecodeHash,
+    /// The backward walk exhausted its rollback budget without finding a height at which the relay
+    /// and local chains agree, so there is no verified ancestor to resubmit from.
+    #[error("No common ancestor found within the configured rollback depth")]
+    NoCommonAncestor,
+    /// The header was deliberately not submitted because it is in its failure-backoff window. A
+    /// distinct error (rather than a success) so the relay loop does not advance past a header that
+    /// was never stored.
+    #[error("Block header submission deferred by failure backoff")]
+    DeferredByBackoff,
+    /// An error returned by the parachain runtime client.
+    #[error("Runtime error: {0}")]
+    RuntimeError(#[from] runtime::Error),
+}
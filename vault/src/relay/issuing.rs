@@ -2,8 +2,56 @@ use super::Error;
 use crate::delay::RandomDelay;
 use async_trait::async_trait;
 use bitcoin::{sha256, Hash};
-use runtime::{BtcRelayPallet, H256Le, InterBtcParachain, RawBlockHeader};
-use std::sync::Arc;
+use quick_cache::sync::Cache;
+use runtime::{
+    AccountId, BtcRelayPallet, CurrencyId, H256Le, InterBtcParachain, RawBlockHeader, RewardPallet,
+};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// Number of block hashes the [`CachedIssuing`] keeps around. The relay only ever queries the
+/// recent tip while catching up, so a few thousand entries is plenty to avoid redundant storage
+/// reads while keeping the footprint bounded.
+const BLOCK_HASH_CACHE_CAPACITY: usize = 4096;
+
+/// Caps bounding how large a single `store_block_headers` extrinsic may grow. A catch-up can span
+/// thousands of 80-byte headers, which submitted as one extrinsic would exceed the parachain block
+/// limits; [`Issuing::submit_block_header_batch`] splits the input into sub-batches that each stay
+/// under these thresholds. Surfaced as config (rather than a hardcoded constant) so operators can
+/// tune it the way the faucet tunes its allowances, and threaded through `sync_from` so the
+/// configured cap applies to reorg catch-ups too.
+#[derive(Clone, Copy, Debug)]
+pub struct HeaderBatchConfig {
+    /// Maximum cumulative size, in bytes, of the headers in a single sub-batch.
+    pub max_batch_bytes: usize,
+}
+
+impl Default for HeaderBatchConfig {
+    fn default() -> Self {
+        // mirrors the ~1MB cap used for bridge message relays
+        Self {
+            max_batch_bytes: 1024 * 1024,
+        }
+    }
+}
+
+/// Compensation a relayer account has accrued for the Bitcoin headers it has submitted, as
+/// reported by the parachain's reward pallet. Returned as a structured figure so a vault operator
+/// can poll what their relaying has earned rather than reconstructing it from `store_block_header`
+/// events.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RelayerReward {
+    /// The relayer account the figure is reported for.
+    pub account_id: AccountId,
+    /// The reward pool the figure is drawn from. The reward pallet keys balances per currency, so
+    /// this records which pool `total` reflects rather than leaving it implicit.
+    pub currency_id: CurrencyId,
+    /// Total reward balance accrued to the account in `currency_id`.
+    pub total: u128,
+}
 
 #[async_trait]
 pub trait Issuing {
@@ -31,10 +79,20 @@ pub trait Issuing {
 
     /// Submit a batch of block headers and wait for inclusion
     ///
+    /// The input is split into sub-batches that each stay under the caps in `config` and submitted
+    /// sequentially, so a catch-up spanning thousands of headers does not overflow the parachain
+    /// block limits. Submission is sequential, so a mid-batch failure leaves the earlier sub-batches
+    /// committed.
+    ///
     /// # Arguments
     ///
     /// * `headers` - Raw block headers (multiple of 80 bytes)
-    async fn submit_block_header_batch(&self, headers: Vec<Vec<u8>>) -> Result<(), Error>;
+    /// * `config` - Size caps that bound each sub-batch
+    async fn submit_block_header_batch(
+        &self,
+        headers: Vec<Vec<u8>>,
+        config: &HeaderBatchConfig,
+    ) -> Result<(), Error>;
 
     /// Returns the light client's chain tip
     async fn get_best_height(&self) -> Result<u32, Error>;
@@ -54,6 +112,119 @@ pub trait Issuing {
     ///
     /// * `hash_le` - Hash (little-endian) of the block
     async fn is_block_stored(&self, hash_le: Vec<u8>) -> Result<bool, Error>;
+
+    /// Reconcile the light client's chain with the local Bitcoin chain after a reorganization.
+    ///
+    /// Walks backward from the lower of the light client's tip and `local_tip_height` (the highest
+    /// height both chains have), comparing the stored hash at each height against the caller's
+    /// local hash at the same height to find the highest common ancestor, then
+    /// resubmits the divergent suffix of local headers in order (skipping any that are already
+    /// stored). Headers that are already stored are left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `local` - Accessor for the local Bitcoin chain
+    /// * `local_tip_height` - Height of the local chain tip
+    /// * `init_height` - Height the light client was initialized at; the backward walk never
+    ///   descends below it
+    /// * `max_rollback` - Maximum number of blocks to walk back before giving up, to bound the scan.
+    ///   If no common ancestor is found within this budget the method errors rather than
+    ///   resubmitting from an unverified height.
+    /// * `config` - Size caps applied to the resubmission batch, so a reorg catch-up honours the
+    ///   same operator-tuned limits as a forward catch-up
+    ///
+    /// Returns the raw headers that were submitted, in ascending height order.
+    async fn sync_from(
+        &self,
+        local: &(dyn LocalBtcChain + Send + Sync),
+        local_tip_height: u32,
+        init_height: u32,
+        max_rollback: u32,
+        config: &HeaderBatchConfig,
+    ) -> Result<Vec<Vec<u8>>, Error>;
+}
+
+/// Read-only accessor for a relayer's accrued rewards, surfaced alongside [`Issuing`] so a vault
+/// operator running the relayer can poll accumulated compensation for submitted headers instead of
+/// scraping events. Kept as a separate trait because, unlike [`Issuing`], it reads reward-pallet
+/// state rather than the light client.
+#[async_trait]
+pub trait RelayerRewardsInfo {
+    /// Returns the reward balance accrued to `account_id` in the `currency_id` reward pool for the
+    /// Bitcoin headers it has submitted.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id` - Relayer identity to report rewards for
+    /// * `currency_id` - Reward pool to read the balance from; the reward pallet keys balances per
+    ///   currency, so the pool must be specified rather than assumed
+    async fn get_relayer_reward(
+        &self,
+        account_id: AccountId,
+        currency_id: CurrencyId,
+    ) -> Result<RelayerReward, Error>;
+}
+
+/// Read-only accessor for the relayer's local view of the Bitcoin chain, used by
+/// [`Issuing::sync_from`] to locate the highest common ancestor and fetch the headers that need to
+/// be resubmitted after a fork.
+#[async_trait]
+pub trait LocalBtcChain {
+    /// Hash (little-endian) of the local block at the given height.
+    async fn hash_at(&self, height: u32) -> Result<Vec<u8>, Error>;
+
+    /// Raw (80-byte) header of the local block at the given height.
+    async fn header_at(&self, height: u32) -> Result<Vec<u8>, Error>;
+}
+
+/// Decision taken at a single height of [`Issuing::sync_from`]'s backward walk for the highest
+/// common ancestor.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum AncestorStep {
+    /// Relay and local agree at this height; it is the common ancestor.
+    Found(u32),
+    /// No agreement yet and there is still budget to walk back.
+    Continue,
+    /// Reached the rollback floor without agreeing, so the fork is deeper than `max_rollback`
+    /// (or predates the init height) and there is no verified ancestor to build on.
+    GiveUp,
+}
+
+/// Splits `headers` into `[start, end)` source ranges that each stay under `max_batch_bytes`,
+/// always making progress on at least one header even if it alone exceeds the cap. Factored out so
+/// the boundary arithmetic is unit-testable without a live parachain.
+fn plan_header_batches(headers: &[Vec<u8>], max_batch_bytes: usize) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut chunk_start = 0;
+    while chunk_start < headers.len() {
+        let mut chunk_end = chunk_start;
+        let mut chunk_bytes = 0;
+        while chunk_end < headers.len() {
+            let next = chunk_bytes + headers[chunk_end].len();
+            // always make progress on at least one header, even if it alone exceeds the cap
+            if chunk_end > chunk_start && next > max_batch_bytes {
+                break;
+            }
+            chunk_bytes = next;
+            chunk_end += 1;
+        }
+        ranges.push((chunk_start, chunk_end));
+        chunk_start = chunk_end;
+    }
+    ranges
+}
+
+/// Pure termination logic for the backward walk: prefer a confirmed hash match, otherwise keep
+/// walking while above `floor`, and give up once the floor is reached without a match. Factored out
+/// so the boundary cases are unit-testable without a live parachain.
+fn ancestor_step(height: u32, floor: u32, hashes_match: bool) -> AncestorStep {
+    if hashes_match {
+        AncestorStep::Found(height)
+    } else if height <= floor {
+        AncestorStep::GiveUp
+    } else {
+        AncestorStep::Continue
+    }
 }
 
 #[async_trait]
@@ -94,16 +265,28 @@ impl Issuing for InterBtcParachain {
     }
 
     #[tracing::instrument(name = "submit_block_header_batch", skip(self, headers))]
-    async fn submit_block_header_batch(&self, headers: Vec<Vec<u8>>) -> Result<(), Error> {
-        BtcRelayPallet::store_block_headers(
-            self,
-            headers
+    async fn submit_block_header_batch(
+        &self,
+        headers: Vec<Vec<u8>>,
+        config: &HeaderBatchConfig,
+    ) -> Result<(), Error> {
+        // split into sub-batches that each stay under the byte cap, tracking the source range of
+        // each chunk so a failure can report which headers did not make it in
+        for (chunk_start, chunk_end) in plan_header_batches(&headers, config.max_batch_bytes) {
+            let chunk = headers[chunk_start..chunk_end]
                 .iter()
                 .map(|header| RawBlockHeader(header.to_vec()))
-                .collect::<Vec<_>>(),
-        )
-        .await
-        .map_err(Into::into)
+                .collect::<Vec<_>>();
+
+            if let Err(err) = BtcRelayPallet::store_block_headers(self, chunk).await {
+                // earlier sub-batches are already committed; report the range that failed
+                tracing::warn!(
+                    "failed to submit block header range [{chunk_start}, {chunk_end}): {err}"
+                );
+                return Err(err.into());
+            }
+        }
+        Ok(())
     }
 
     async fn get_best_height(&self) -> Result<u32, Error> {
@@ -119,4 +302,384 @@ impl Issuing for InterBtcParachain {
         let head = BtcRelayPallet::get_block_header(self, H256Le::from_bytes_le(&hash_le)).await?;
         Ok(head.block_height > 0)
     }
+
+    #[tracing::instrument(name = "sync_from", skip(self, local))]
+    async fn sync_from(
+        &self,
+        local: &(dyn LocalBtcChain + Send + Sync),
+        local_tip_height: u32,
+        init_height: u32,
+        max_rollback: u32,
+        config: &HeaderBatchConfig,
+    ) -> Result<Vec<Vec<u8>>, Error> {
+        let relay_tip = self.get_best_height().await?;
+
+        // only heights that exist on both chains can be a common ancestor
+        let mut height = relay_tip.min(local_tip_height);
+        let floor = init_height.max(height.saturating_sub(max_rollback));
+
+        // walk backward until the relay and local chains agree on the hash at `height`; bail out if
+        // the rollback budget is exhausted without a confirmed match rather than resubmitting from
+        // an unverified height, which would produce headers that don't connect to the stored chain
+        let common_ancestor = loop {
+            let hashes_match = self.get_block_hash(height).await? == local.hash_at(height).await?;
+            match ancestor_step(height, floor, hashes_match) {
+                AncestorStep::Found(height) => break height,
+                AncestorStep::GiveUp => return Err(Error::NoCommonAncestor),
+                AncestorStep::Continue => height -= 1,
+            }
+        };
+
+        // resubmit the divergent suffix in ascending order, skipping already-stored headers
+        let mut submitted = Vec::new();
+        for height in (common_ancestor + 1)..=local_tip_height {
+            let header = local.header_at(height).await?;
+            let hash_le = RawBlockHeader(header.clone()).hash().to_bytes_le().to_vec();
+            if self.is_block_stored(hash_le).await? {
+                continue;
+            }
+            submitted.push(header);
+        }
+
+        if !submitted.is_empty() {
+            self.submit_block_header_batch(submitted.clone(), config).await?;
+        }
+
+        Ok(submitted)
+    }
+}
+
+#[async_trait]
+impl RelayerRewardsInfo for InterBtcParachain {
+    async fn get_relayer_reward(
+        &self,
+        account_id: AccountId,
+        currency_id: CurrencyId,
+    ) -> Result<RelayerReward, Error> {
+        let total = RewardPallet::compute_reward(self, &account_id, currency_id).await?;
+        Ok(RelayerReward {
+            account_id,
+            currency_id,
+            total,
+        })
+    }
+}
+
+/// Tunables for how a header that keeps failing to store is backed off and reported.
+#[derive(Clone, Copy, Debug)]
+pub struct FailureBackoffConfig {
+    /// Base delay before the first retry; doubles with each consecutive failure.
+    pub base_backoff: Duration,
+    /// Upper bound on the backoff delay.
+    pub max_backoff: Duration,
+    /// Number of consecutive failures after which a structured warning is emitted.
+    pub warn_threshold: u32,
+}
+
+impl Default for FailureBackoffConfig {
+    fn default() -> Self {
+        Self {
+            base_backoff: Duration::from_secs(30),
+            max_backoff: Duration::from_secs(60 * 60),
+            warn_threshold: 5,
+        }
+    }
+}
+
+impl FailureBackoffConfig {
+    /// Backoff delay after `count` consecutive failures: exponential from `base_backoff`, doubling
+    /// on each failure and clamped to `max_backoff`. The shift is capped so the multiplier cannot
+    /// overflow before the `max_backoff` clamp takes over.
+    fn backoff_delay(&self, count: u32) -> Duration {
+        self.base_backoff
+            .saturating_mul(1u32 << count.saturating_sub(1).min(16))
+            .min(self.max_backoff)
+    }
+}
+
+/// Bookkeeping for a single header that has failed to store.
+struct FailureRecord {
+    count: u32,
+    last_attempt: Instant,
+}
+
+/// Wraps an [`Issuing`] with a bounded, sharded LRU of block hashes that are known to be stored on
+/// the parachain, plus a failure blacklist that backs off poison headers. Under the `random_delay`
+/// concurrency many vaults race to relay the same headers; caching the hashes lets us short-circuit
+/// the `is_block_stored` round-trip on a cache hit instead of flooding the node with redundant
+/// storage reads.
+///
+/// A hash is inserted once we have confirmed it is stored (either `is_block_stored == true` or a
+/// successful `store_block_header`). The cache is a bounded LRU and only evicts under capacity
+/// pressure — it has no TTL, so the hot tip hashes (exactly the ones a reorg would invalidate) do
+/// not age out on their own at normal throughput. A header that a reorg may have orphaned must
+/// therefore be dropped explicitly via [`CachedIssuing::invalidate`] rather than relied upon to
+/// expire.
+///
+/// A header that fails to store is recorded with its consecutive failure count and last-attempt
+/// time. Borrowing the "blacklist bad hashes upon failure, clear on successful import" pattern from
+/// snapshot restore, re-attempts are held off with exponential backoff so a single poison header
+/// cannot wedge the relay loop, and a structured warning is emitted once it crosses
+/// [`FailureBackoffConfig::warn_threshold`]. Observing the header as stored clears the entry.
+pub struct CachedIssuing<T> {
+    inner: T,
+    stored_hashes: Arc<Cache<[u8; 32], ()>>,
+    failures: Arc<Mutex<HashMap<[u8; 32], FailureRecord>>>,
+    backoff: FailureBackoffConfig,
+}
+
+impl<T> CachedIssuing<T> {
+    pub fn new(inner: T) -> Self {
+        Self::with_backoff(inner, FailureBackoffConfig::default())
+    }
+
+    pub fn with_backoff(inner: T, backoff: FailureBackoffConfig) -> Self {
+        Self {
+            inner,
+            stored_hashes: Arc::new(Cache::new(BLOCK_HASH_CACHE_CAPACITY)),
+            failures: Arc::new(Mutex::new(HashMap::new())),
+            backoff,
+        }
+    }
+
+    fn mark_stored(&self, hash_le: &[u8]) {
+        if let Ok(key) = <[u8; 32]>::try_from(hash_le) {
+            self.stored_hashes.insert(key, ());
+            self.failures.lock().unwrap().remove(&key);
+        }
+    }
+
+    /// Drops `hash_le` from the stored-hash cache so the next `is_block_stored`/`submit_block_header`
+    /// for it goes back to the parachain. Used by reorg recovery to forget a header that may have
+    /// been orphaned, since the LRU has no TTL and would otherwise keep reporting it as stored.
+    pub fn invalidate(&self, hash_le: &[u8]) {
+        if let Ok(key) = <[u8; 32]>::try_from(hash_le) {
+            self.stored_hashes.remove(&key);
+        }
+    }
+
+    fn is_cached(&self, hash_le: &[u8]) -> bool {
+        <[u8; 32]>::try_from(hash_le)
+            .map(|key| self.stored_hashes.get(&key).is_some())
+            .unwrap_or(false)
+    }
+
+    /// Returns true if `hash_le` failed recently and its backoff window has not yet elapsed, in
+    /// which case the caller should skip re-attempting it this pass.
+    fn in_backoff(&self, hash_le: &[u8]) -> bool {
+        let Ok(key) = <[u8; 32]>::try_from(hash_le) else {
+            return false;
+        };
+        let failures = self.failures.lock().unwrap();
+        let Some(record) = failures.get(&key) else {
+            return false;
+        };
+        record.last_attempt.elapsed() < self.backoff.backoff_delay(record.count)
+    }
+
+    /// Records a failed store attempt for `hash_le`, emitting a warning once the header crosses the
+    /// configured failure threshold.
+    fn record_failure(&self, hash_le: &[u8]) {
+        let Ok(key) = <[u8; 32]>::try_from(hash_le) else {
+            return;
+        };
+        let mut failures = self.failures.lock().unwrap();
+        let record = failures.entry(key).or_insert(FailureRecord {
+            count: 0,
+            last_attempt: Instant::now(),
+        });
+        record.count += 1;
+        record.last_attempt = Instant::now();
+        if record.count >= self.backoff.warn_threshold {
+            tracing::warn!(
+                count = record.count,
+                hash = hex::encode(key),
+                "block header repeatedly failed to store; backing off"
+            );
+        }
+    }
+}
+
+#[async_trait]
+impl<T: Issuing + Send + Sync> Issuing for CachedIssuing<T> {
+    async fn is_initialized(&self) -> Result<bool, Error> {
+        self.inner.is_initialized().await
+    }
+
+    async fn initialize(&self, header: Vec<u8>, height: u32) -> Result<(), Error> {
+        self.inner.initialize(header, height).await
+    }
+
+    async fn submit_block_header(
+        &self,
+        header: Vec<u8>,
+        random_delay: Arc<Box<dyn RandomDelay + Send + Sync>>,
+    ) -> Result<(), Error> {
+        let hash_le = RawBlockHeader(header.clone()).hash().to_bytes_le().to_vec();
+        if self.is_cached(&hash_le) {
+            return Ok(());
+        }
+        // hold off re-attempting a header that keeps failing so it can't wedge the relay loop.
+        // Report this as a distinct error rather than `Ok(())`: the header was deliberately not
+        // stored, so faking success would let the relay advance its cursor past it and skip the
+        // poison header forever.
+        if self.in_backoff(&hash_le) {
+            return Err(Error::DeferredByBackoff);
+        }
+        match self.inner.submit_block_header(header, random_delay).await {
+            Ok(()) => {
+                self.mark_stored(&hash_le);
+                Ok(())
+            }
+            Err(err) => {
+                self.record_failure(&hash_le);
+                Err(err)
+            }
+        }
+    }
+
+    async fn submit_block_header_batch(
+        &self,
+        headers: Vec<Vec<u8>>,
+        config: &HeaderBatchConfig,
+    ) -> Result<(), Error> {
+        self.inner.submit_block_header_batch(headers, config).await
+    }
+
+    async fn get_best_height(&self) -> Result<u32, Error> {
+        self.inner.get_best_height().await
+    }
+
+    async fn get_block_hash(&self, height: u32) -> Result<Vec<u8>, Error> {
+        self.inner.get_block_hash(height).await
+    }
+
+    async fn is_block_stored(&self, hash_le: Vec<u8>) -> Result<bool, Error> {
+        if self.is_cached(&hash_le) {
+            return Ok(true);
+        }
+        let stored = self.inner.is_block_stored(hash_le.clone()).await?;
+        if stored {
+            self.mark_stored(&hash_le);
+        }
+        Ok(stored)
+    }
+
+    async fn sync_from(
+        &self,
+        local: &(dyn LocalBtcChain + Send + Sync),
+        local_tip_height: u32,
+        init_height: u32,
+        max_rollback: u32,
+        config: &HeaderBatchConfig,
+    ) -> Result<Vec<Vec<u8>>, Error> {
+        // capture the relay's pre-reorg hashes over the range the walk may rewrite, mirroring the
+        // floor the inner walk uses. Any of these that a reorg orphans must be dropped from the
+        // cache afterwards: the LRU has no TTL, so an orphaned hash would otherwise stay cached as
+        // "stored" indefinitely and keep short-circuiting its own resubmission.
+        let relay_tip = self.inner.get_best_height().await?;
+        let floor = init_height.max(relay_tip.min(local_tip_height).saturating_sub(max_rollback));
+        let mut prior_hashes = Vec::new();
+        for height in (floor + 1)..=relay_tip {
+            prior_hashes.push(self.inner.get_block_hash(height).await?);
+        }
+
+        let submitted = self
+            .inner
+            .sync_from(local, local_tip_height, init_height, max_rollback, config)
+            .await?;
+
+        // forget the old-fork hashes, then record the freshly submitted suffix as stored
+        for hash_le in prior_hashes {
+            self.invalidate(&hash_le);
+        }
+        for header in &submitted {
+            let hash_le = RawBlockHeader(header.clone()).hash().to_bytes_le().to_vec();
+            self.mark_stored(&hash_le);
+        }
+        Ok(submitted)
+    }
+}
+
+#[async_trait]
+impl<T: RelayerRewardsInfo + Send + Sync> RelayerRewardsInfo for CachedIssuing<T> {
+    async fn get_relayer_reward(
+        &self,
+        account_id: AccountId,
+        currency_id: CurrencyId,
+    ) -> Result<RelayerReward, Error> {
+        self.inner.get_relayer_reward(account_id, currency_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(sizes: &[usize]) -> Vec<Vec<u8>> {
+        sizes.iter().map(|&n| vec![0u8; n]).collect()
+    }
+
+    #[test]
+    fn plan_header_batches_groups_under_cap() {
+        // three 80-byte headers with a 200-byte cap pack two then one
+        let ranges = plan_header_batches(&headers(&[80, 80, 80]), 200);
+        assert_eq!(ranges, vec![(0, 2), (2, 3)]);
+    }
+
+    #[test]
+    fn plan_header_batches_makes_progress_on_oversized_header() {
+        // a single header larger than the cap still gets its own sub-batch rather than stalling
+        let ranges = plan_header_batches(&headers(&[500, 80]), 200);
+        assert_eq!(ranges, vec![(0, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn plan_header_batches_handles_empty_input() {
+        assert!(plan_header_batches(&headers(&[]), 200).is_empty());
+    }
+
+    #[test]
+    fn ancestor_step_matches_at_current_height() {
+        assert_eq!(ancestor_step(100, 10, true), AncestorStep::Found(100));
+        // a match exactly at the floor is still a valid ancestor
+        assert_eq!(ancestor_step(10, 10, true), AncestorStep::Found(10));
+    }
+
+    #[test]
+    fn ancestor_step_keeps_walking_above_floor() {
+        assert_eq!(ancestor_step(100, 10, false), AncestorStep::Continue);
+    }
+
+    #[test]
+    fn ancestor_step_gives_up_at_floor_without_match() {
+        // reaching the rollback floor with no matching hash means the fork is too deep
+        assert_eq!(ancestor_step(10, 10, false), AncestorStep::GiveUp);
+        assert_eq!(ancestor_step(5, 10, false), AncestorStep::GiveUp);
+    }
+
+    #[test]
+    fn backoff_delay_grows_exponentially() {
+        let config = FailureBackoffConfig {
+            base_backoff: Duration::from_secs(30),
+            max_backoff: Duration::from_secs(60 * 60),
+            warn_threshold: 5,
+        };
+        // doubles with each consecutive failure starting from the base delay
+        assert_eq!(config.backoff_delay(1), Duration::from_secs(30));
+        assert_eq!(config.backoff_delay(2), Duration::from_secs(60));
+        assert_eq!(config.backoff_delay(3), Duration::from_secs(120));
+    }
+
+    #[test]
+    fn backoff_delay_clamps_to_max() {
+        let config = FailureBackoffConfig {
+            base_backoff: Duration::from_secs(30),
+            max_backoff: Duration::from_secs(60 * 60),
+            warn_threshold: 5,
+        };
+        // a large failure count saturates at max_backoff rather than overflowing the shift
+        assert_eq!(config.backoff_delay(20), Duration::from_secs(60 * 60));
+        assert_eq!(config.backoff_delay(u32::MAX), Duration::from_secs(60 * 60));
+    }
 }
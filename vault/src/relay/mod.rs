@@ -0,0 +1,5 @@
+mod error;
+mod issuing;
+
+pub use error::Error;
+pub use issuing::*;